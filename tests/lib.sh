@@ -0,0 +1,30 @@
+# Common setup of the tests, sourced by each of them: mdevctl manages a fake
+# root in a temporary directory, with one PCI parent device providing one type
+
+srcdir=$(cd "$(dirname "$0")/.." && pwd)
+mdevctl="$srcdir/mdevctl"
+
+parent=0000:00:02.0
+type=i915-GVTg_V5_4
+uuid=11111111-2222-3333-4444-555555555555
+
+root=$(mktemp -d)
+trap 'rm -rf "$root"' EXIT
+
+unset MDEVCTL_PERSIST_DIR MDEVCTL_MDEV_BASE MDEVCTL_STRICT MDEVCTL_CHECKSUM \
+      MDEVCTL_LOG_FORMAT MDEVCTL_LOG_LEVEL
+export MDEVCTL_ROOT="$root"
+export MDEVCTL_LOG_TARGET=none
+
+parent_dir="$root/sys/devices/pci0000:00/$parent"
+mkdir -p "$parent_dir/mdev_supported_types/$type" "$root/sys/class/mdev_bus" \
+         "$root/sys/bus/mdev/devices" "$root/etc/mdevctl.d"
+echo 2 > "$parent_dir/mdev_supported_types/$type/available_instances"
+echo vfio-pci > "$parent_dir/mdev_supported_types/$type/device_api"
+echo GVTg_V5_4 > "$parent_dir/mdev_supported_types/$type/name"
+ln -s "../../devices/pci0000:00/$parent" "$root/sys/class/mdev_bus/$parent"
+
+fail() {
+    echo "FAIL: $(basename "$0"): $*" >&2
+    exit 1
+}
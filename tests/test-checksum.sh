@@ -0,0 +1,31 @@
+#!/bin/bash
+# A config modified outside of mdevctl is a warning with --checksum=warn,
+# silenced by --quiet, and an error with --checksum=fail
+
+. "$(dirname "$0")/lib.sh"
+
+config="$root/etc/mdevctl.d/$parent/$uuid"
+
+modify_outside() {
+    jq ".description = \"$1\"" "$config" > "$config.new" && mv "$config.new" "$config"
+}
+
+"$mdevctl" define -u "$uuid" -p "$parent" -t "$type" --checksum=warn ||
+    fail "define failed"
+
+modify_outside outside
+out=$("$mdevctl" modify -u "$uuid" --auto --checksum=warn 2>&1) ||
+    fail "modify with --checksum=warn failed"
+[ "$out" == "Warning: config $config was modified outside of mdevctl" ] ||
+    fail "unexpected output with --checksum=warn: $out"
+
+modify_outside again
+out=$("$mdevctl" modify -u "$uuid" --manual --checksum=warn -q 2>&1) ||
+    fail "modify with --checksum=warn -q exited with $?"
+[ -z "$out" ] || fail "unexpected output with --checksum=warn -q: $out"
+
+modify_outside failing
+"$mdevctl" list -d --checksum=fail 2> /dev/null | grep -q "$uuid" &&
+    fail "modified config listed with --checksum=fail"
+
+exit 0
@@ -0,0 +1,32 @@
+#!/bin/bash
+# While a long-running mdevctl holds the daemon lock, start and stop are
+# refused with EXIT_BUSY unless forced
+
+. "$(dirname "$0")/lib.sh"
+
+"$mdevctl" define -u "$uuid" -p "$parent" -t "$type" || fail "define failed"
+
+mkdir -p "$root/run/mdevctl"
+echo "autostart, pid 1" > "$root/run/mdevctl/daemon.pid"
+flock "$root/run/mdevctl/daemon.lock" sleep 30 &
+daemon=$!
+trap 'kill $daemon 2> /dev/null; rm -rf "$root"' EXIT
+while flock -n "$root/run/mdevctl/daemon.lock" true; do
+    sleep 0.1
+done
+
+rc=0
+out=$("$mdevctl" start -u "$uuid" 2>&1) || rc=$?
+[ $rc -eq 7 ] || fail "start exited with $rc rather than 7: $out"
+[[ "$out" == *"use --force"* ]] || fail "unexpected output of start: $out"
+[ -e "$root/sys/bus/mdev/devices/$uuid" ] && fail "device started"
+
+rc=0
+"$mdevctl" stop -u "$uuid" > /dev/null 2>&1 || rc=$?
+[ $rc -eq 7 ] || fail "stop exited with $rc rather than 7"
+
+rc=0
+out=$("$mdevctl" start -u "$uuid" --force 2>&1) || rc=$?
+[ $rc -ne 7 ] || fail "start --force refused: $out"
+
+exit 0
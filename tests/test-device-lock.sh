@@ -0,0 +1,33 @@
+#!/bin/bash
+# define takes the lock of a device whose UUID is read from --xmlfile, such
+# that it waits for another mdevctl operating on the device
+
+. "$(dirname "$0")/lib.sh"
+
+command -v xmllint > /dev/null || exit 0
+
+cat > "$root/device.xml" <<XML
+<device>
+  <parent>pci_0000_00_02_0</parent>
+  <capability type='mdev'>
+    <type id='$type'/>
+    <uuid>$uuid</uuid>
+  </capability>
+</device>
+XML
+
+mkdir -p "$root/run/mdevctl/lock"
+flock "$root/run/mdevctl/lock/$uuid" sleep 2 &
+holder=$!
+trap 'kill $holder 2> /dev/null; rm -rf "$root"' EXIT
+while flock -n "$root/run/mdevctl/lock/$uuid" true; do
+    sleep 0.1
+done
+
+out=$("$mdevctl" define --xmlfile="$root/device.xml" 2>&1) ||
+    fail "define --xmlfile failed: $out"
+[[ "$out" == *"Waiting for another mdevctl operating on $uuid"* ]] ||
+    fail "define --xmlfile did not wait for the device lock: $out"
+[ -e "$root/etc/mdevctl.d/$parent/$uuid" ] || fail "device not defined"
+
+exit 0
@@ -0,0 +1,25 @@
+#!/bin/bash
+# Generated units treat a device already running as started and existing
+# units are only overwritten with --force
+
+. "$(dirname "$0")/lib.sh"
+
+unit="$root/etc/systemd/system/mdev@$uuid.service"
+
+"$mdevctl" define -u "$uuid" -p "$parent" -t "$type" || fail "define failed"
+
+"$mdevctl" generate-systemd > /dev/null || fail "generate-systemd failed"
+grep -q "^SuccessExitStatus=3$" "$unit" || fail "unit lacks SuccessExitStatus=3"
+grep -q "^ExecStart=.* start --uuid=$uuid --parent=$parent --force$" "$unit" ||
+    fail "unit lacks ExecStart with --force"
+
+echo "# edited" >> "$unit"
+rc=0
+"$mdevctl" generate-systemd > /dev/null 2>&1 || rc=$?
+[ $rc -eq 3 ] || fail "generate-systemd exited with $rc rather than 3"
+grep -q "^# edited$" "$unit" || fail "existing unit overwritten"
+
+"$mdevctl" generate-systemd --force > /dev/null || fail "generate-systemd --force failed"
+grep -q "^# edited$" "$unit" && fail "existing unit not overwritten with --force"
+
+exit 0
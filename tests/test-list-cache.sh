@@ -0,0 +1,25 @@
+#!/bin/bash
+# A cached list entry is refreshed by a change of its config within the
+# same second which keeps its inode and size
+
+. "$(dirname "$0")/lib.sh"
+
+# Listings are only cached with a uevent sequence number
+mkdir -p "$root/sys/kernel"
+echo 1 > "$root/sys/kernel/uevent_seqnum"
+
+"$mdevctl" define -u "$uuid" -p "$parent" -t "$type" || fail "define failed"
+"$mdevctl" modify -u "$uuid" --description=a || fail "modify failed"
+config="$root/etc/mdevctl.d/$parent/$uuid"
+touch -d @1700000000 "$config"
+"$mdevctl" list -d -v | grep -q "Description: a" ||
+    fail "description a not listed"
+
+# Edited in place, keeping the inode as well, half a second later
+edited=$(jq '.description = "b"' "$config")
+echo "$edited" > "$config"
+touch -d @1700000000.5 "$config"
+"$mdevctl" list -d -v | grep -q "Description: b" ||
+    fail "stale description listed from the cache"
+
+exit 0
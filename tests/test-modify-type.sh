@@ -0,0 +1,20 @@
+#!/bin/bash
+# modify resolves a type name on the parent of the config without --parent,
+# and refuses types the parent does not support
+
+. "$(dirname "$0")/lib.sh"
+
+config="$root/etc/mdevctl.d/$parent/$uuid"
+
+"$mdevctl" define -u "$uuid" -p "$parent" -t "$type" || fail "define failed"
+
+"$mdevctl" modify -u "$uuid" -t GVTg_V5_4 || fail "modify by type name failed"
+[ "$(jq -r '.mdev_type' "$config")" == "$type" ] ||
+    fail "type name not resolved: $(jq -r '.mdev_type' "$config")"
+
+"$mdevctl" modify -u "$uuid" -t nonexistent 2> /dev/null &&
+    fail "modify to an unsupported type succeeded"
+[ "$(jq -r '.mdev_type' "$config")" == "$type" ] ||
+    fail "unsupported type written: $(jq -r '.mdev_type' "$config")"
+
+exit 0
@@ -0,0 +1,36 @@
+#!/bin/bash
+# Undefining a device whose config cannot be removed below the overlay
+# leaves a whiteout in the overlay hiding it, until it is defined again
+
+. "$(dirname "$0")/lib.sh"
+
+# The overlay is only used by root
+[ $(id -u) -eq 0 ] || exit 0
+
+overlay="$root/var/lib/mdevctl/overlay.d"
+vendor="$root/usr/lib/mdevctl.d"
+mkdir -p "$overlay" "$vendor/$parent"
+jq -n -M --arg type "$type" '{mdev_type: $type, start: "manual"}' > "$vendor/$parent/$uuid"
+
+"$mdevctl" list -d --show-layer | grep -q "$uuid.*vendor" ||
+    fail "vendor config not listed"
+
+"$mdevctl" undefine -u "$uuid" || fail "undefine failed"
+[ -e "$overlay/$parent/.$uuid.whiteout" ] || fail "no whiteout in the overlay"
+[ -e "$vendor/$parent/$uuid" ] || fail "vendor config removed"
+out=$("$mdevctl" list -d)
+[ -z "$out" ] || fail "undefined device listed: $out"
+"$mdevctl" list -d -u "$uuid" -p "$parent" | grep -q "$uuid" &&
+    fail "undefined device listed by UUID and parent"
+
+"$mdevctl" define -u "$uuid" -p "$parent" -t "$type" || fail "define failed"
+[ -e "$overlay/$parent/.$uuid.whiteout" ] && fail "whiteout left by define"
+"$mdevctl" list -d --show-layer | grep -q "$uuid.*overlay" ||
+    fail "config in the overlay not listed"
+
+"$mdevctl" undefine -u "$uuid" -p "$parent" || fail "undefine failed"
+[ -e "$overlay/$parent/$uuid" ] && fail "config in the overlay left"
+out=$("$mdevctl" list -d)
+[ -z "$out" ] || fail "undefined device listed: $out"
+
+exit 0
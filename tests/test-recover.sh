@@ -0,0 +1,38 @@
+#!/bin/bash
+# recover and cleanup skip the intents of an mdevctl still running, told
+# apart from a later process reusing its pid by the recorded start time
+
+. "$(dirname "$0")/lib.sh"
+
+intents="$root/var/lib/mdevctl/intents"
+mkdir -p "$intents"
+
+starttime=$(cut -d ' ' -f 22 /proc/$$/stat)
+
+intent() {
+    jq -c -M -n --arg uuid "$uuid" --arg parent "$parent" --argjson pid $$ \
+       --arg starttime "$1" \
+       '{op: "start", uuid: $uuid, parent: $parent, pid: $pid,
+         starttime: $starttime}' > "$intents/start-$uuid"
+}
+
+intent "$starttime"
+out=$("$mdevctl" recover 2>&1) || fail "recover failed"
+[[ "$out" == *"in progress by pid $$"* ]] || fail "unexpected output of recover: $out"
+[ -e "$intents/start-$uuid" ] || fail "intent of a running mdevctl recovered"
+
+out=$("$mdevctl" cleanup 2>&1)
+[ -e "$intents/start-$uuid" ] || fail "intent of a running mdevctl cleaned up"
+
+intent "$((starttime + 1))"
+out=$("$mdevctl" cleanup 2>&1)
+[[ "$out" == *"Removed record of interrupted start of $uuid"* ]] ||
+    fail "unexpected output of cleanup: $out"
+
+intent "$((starttime + 1))"
+out=$("$mdevctl" recover 2>&1) || fail "recover failed"
+[[ "$out" == *"Discarded interrupted start of $uuid"* ]] ||
+    fail "unexpected output of recover: $out"
+[ -e "$intents/start-$uuid" ] && fail "intent of a reused pid left"
+
+exit 0